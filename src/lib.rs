@@ -34,6 +34,7 @@
 #[cfg(test)]
 mod test;
 
+use std::collections::HashSet;
 use std::convert::{TryFrom, TryInto};
 use std::io::{self, Write};
 
@@ -65,9 +66,17 @@ pub enum PixelState {
     Text(char),
     /// the pixel a circle filled `@` or not `O`
     Circle(bool),
+    /// The pixel is shaded by anti-aliasing coverage, indexed into [`Self::SHADE_RAMP`] from
+    /// `' '` (no coverage) to `'@'` (full coverage).
+    Shade(u8),
+    /// The pixel is the interior of a filled rectangle or polygon.
+    Fill,
 }
 
 impl PixelState {
+    /// Density ramp `Self::Shade` is indexed into, from emptiest to darkest.
+    const SHADE_RAMP: [char; 10] = [' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
     /// Returns the character to draw.
     const fn to_char(self) -> char {
         match self {
@@ -84,6 +93,8 @@ impl PixelState {
                     'O'
                 }
             }
+            Self::Shade(idx) => Self::SHADE_RAMP[idx as usize],
+            Self::Fill => '#',
         }
     }
 
@@ -94,8 +105,15 @@ impl PixelState {
             (Self::VLine, Self::HLine) => Self::Cross,
             (_, Self::Circle(what)) => Self::Circle(what),
             (Self::Circle(what), _) => Self::Circle(what),
+            (Self::Text(c), Self::Fill) => Self::Text(c),
+            (Self::Fill, Self::Shade(_)) => Self::Fill,
+            (Self::Shade(_), Self::Fill) => Self::Fill,
             (_, Self::Pixel) => Self::Pixel,
             (Self::Pixel, _) => Self::Pixel,
+            (Self::Shade(old), Self::Shade(new)) => Self::Shade(old.max(new)),
+            (Self::Empty, new @ Self::Shade(_)) => new,
+            (_, Self::Shade(_)) => *self,
+            (Self::Shade(_), _) => new_state,
             (_, new) => new,
         };
 
@@ -103,6 +121,18 @@ impl PixelState {
     }
 }
 
+#[derive(Debug, Default, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// How the canvas is packed into text by [`TextDrawingBackend::present`]/`to_string`.
+pub enum RenderMode {
+    #[default]
+    /// One character per pixel.
+    Ascii,
+    /// Packs each 2x4 block of pixels into a single Unicode Braille glyph (U+2800..U+28FF),
+    /// giving 8x the effective resolution in a terminal.
+    Braille,
+}
+
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 /// Text Drawing Backend for the Plotters library.
@@ -113,15 +143,62 @@ pub struct TextDrawingBackend {
     pub size_y: u32,
     /// Pixel of the canvas.
     pub pixels: Vec<PixelState>,
+    /// How the canvas is packed into text on render.
+    pub mode: RenderMode,
+    /// Last color written to each pixel, in parallel with [`Self::pixels`].
+    pub colors: Vec<Option<(u8, u8, u8)>>,
+    /// Whether [`Self::present_colored_to`] should emit ANSI truecolor escape sequences.
+    pub color: bool,
 }
 
 impl TextDrawingBackend {
     /// Creates a new `TextDrawingBackend` with the given size.
     pub fn new(size_x: u32, size_y: u32) -> Self {
+        let len = (size_x * size_y).try_into().unwrap();
         Self {
             size_x,
             size_y,
-            pixels: vec![PixelState::Empty; (size_x * size_y).try_into().unwrap()],
+            pixels: vec![PixelState::Empty; len],
+            mode: RenderMode::default(),
+            colors: vec![None; len],
+            color: false,
+        }
+    }
+
+    /// Getter on the render mode.
+    pub const fn mode(&self) -> RenderMode {
+        self.mode
+    }
+
+    /// Sets the render mode, returning `self` for chaining.
+    #[must_use]
+    pub const fn with_mode(mut self, mode: RenderMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Getter on whether ANSI truecolor output is enabled.
+    pub const fn color(&self) -> bool {
+        self.color
+    }
+
+    /// Enables or disables ANSI truecolor output, returning `self` for chaining.
+    #[must_use]
+    pub const fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Getter on the recorded per-pixel colors.
+    pub fn colors(&self) -> &[Option<(u8, u8, u8)>] {
+        &self.colors
+    }
+
+    /// Records the last color written at the given position, in parallel with [`Self::pixels`].
+    fn set_color(&mut self, pos_x: usize, pos_y: usize, color: BackendColor) {
+        let index: usize = pos_x + pos_y * usize::try_from(self.size_x).unwrap();
+        if index < self.colors.len() {
+            self.colors[index] = Some(color.rgb);
         }
     }
 
@@ -165,6 +242,171 @@ impl TextDrawingBackend {
             self.pixels[index].update(p);
         }
     }
+
+    /// Writes the canvas as text into `w`, respecting [`Self::mode`].
+    ///
+    /// This is the writer-generic core of [`DrawingBackend::present`], letting callers
+    /// capture the rendered plot into a file, an in-memory buffer or anything else that
+    /// implements [`Write`] instead of only `stderr`.
+    ///
+    /// # Errors
+    /// Returns an error if writing to `w` fails.
+    pub fn present_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "{}", self.render())
+    }
+
+    /// Writes the canvas as text into `w`, one character per pixel, wrapping each glyph in an
+    /// ANSI truecolor escape sequence (`\x1b[38;2;{r};{g};{b}m ... \x1b[0m`) when [`Self::color`]
+    /// is enabled and the pixel has a recorded color. Falls back to [`Self::present_to`] when
+    /// [`Self::color`] is disabled.
+    ///
+    /// Colored output is always rendered one character per pixel and ignores [`Self::mode`] —
+    /// there's no single representative color for a multi-pixel [`RenderMode::Braille`] glyph,
+    /// so this always renders in [`RenderMode::Ascii`] regardless of the configured mode.
+    ///
+    /// # Errors
+    /// Returns an error if writing to `w` fails.
+    pub fn present_colored_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        if !self.color {
+            return self.present_to(w);
+        }
+
+        for r in 0..self.size_y {
+            for c in 0..self.size_x {
+                let index = (r * self.size_x + c) as usize;
+                let ch = self.pixels[index].to_char();
+                match self.colors[index] {
+                    Some((red, green, blue)) => {
+                        write!(w, "\x1b[38;2;{};{};{}m{}\x1b[0m", red, green, blue, ch)?;
+                    }
+                    None => write!(w, "{}", ch)?,
+                }
+            }
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+
+    /// Renders the canvas as text, respecting [`Self::mode`]. This is what backs `to_string()`.
+    fn render(&self) -> String {
+        match self.mode {
+            RenderMode::Ascii => self.render_ascii(),
+            RenderMode::Braille => self.render_braille(),
+        }
+    }
+
+    /// Renders the canvas with one character per pixel.
+    fn render_ascii(&self) -> String {
+        let mut out = String::new();
+        for r in 0..self.size_y {
+            for c in 0..self.size_x {
+                out.push(self.pixels[(r * self.size_x + c) as usize].to_char());
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Renders the canvas by packing each 2x4 block of pixels into a Braille glyph.
+    #[allow(clippy::integer_division)]
+    fn render_braille(&self) -> String {
+        let cols = (self.size_x + 1) / 2;
+        let rows = (self.size_y + 3) / 4;
+
+        let mut out = String::new();
+        for cy in 0..rows {
+            for cx in 0..cols {
+                out.push(self.braille_cell(cx, cy));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Packs the 2x4 subpixel block at Braille cell `(cx, cy)` into a single glyph.
+    ///
+    /// Falls back to a pixel's own ASCII glyph when the block contains a `Text` or `Circle`
+    /// state, so captions and markers stay readable instead of being flattened into dots.
+    ///
+    /// Treats `Shade(0)` the same as `Empty` so a negligible-coverage pixel doesn't light a dot
+    /// here that the Ascii renderer draws as blank.
+    #[allow(clippy::cast_possible_truncation)]
+    fn braille_cell(&self, cx: u32, cy: u32) -> char {
+        const BITS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+        let mut mask: u8 = 0;
+        let mut fallback = None;
+
+        for (row, bits) in BITS.iter().enumerate() {
+            for (col, &bit) in bits.iter().enumerate() {
+                let x = cx * 2 + col as u32;
+                let y = cy * 4 + row as u32;
+                if x >= self.size_x || y >= self.size_y {
+                    continue;
+                }
+
+                let state = self.pixels[(y * self.size_x + x) as usize];
+                if state == PixelState::Empty || matches!(state, PixelState::Shade(0)) {
+                    continue;
+                }
+
+                if matches!(state, PixelState::Text(_) | PixelState::Circle(_)) && fallback.is_none() {
+                    fallback = Some(state);
+                }
+                mask |= bit;
+            }
+        }
+
+        fallback.map_or_else(
+            || char::from_u32(0x2800_u32 + u32::from(mask)).unwrap_or(' '),
+            PixelState::to_char,
+        )
+    }
+
+}
+
+/// Minimal [`DrawingBackend`] that only records the coordinates passed to `draw_pixel`.
+///
+/// Used to capture the points `plotters_backend::rasterizer`'s circle routine visits without
+/// running them through [`TextDrawingBackend::draw_pixel`] (which would turn them into
+/// [`PixelState::Shade`] instead of [`PixelState::Circle`]).
+struct CirclePointCollector {
+    size_x: u32,
+    size_y: u32,
+    points: Vec<(i32, i32)>,
+}
+
+impl DrawingBackend for CirclePointCollector {
+    type ErrorType = std::io::Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        (self.size_x, self.size_y)
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: (i32, i32),
+        _color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.points.push(point);
+        Ok(())
+    }
+
+    fn estimate_text_size<S: BackendTextStyle>(
+        &self,
+        _text: &str,
+        _style: &S,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        Ok((0, 0))
+    }
 }
 
 impl Default for TextDrawingBackend {
@@ -182,6 +424,15 @@ impl<'a> IntoIterator for &'a TextDrawingBackend {
     }
 }
 
+impl std::fmt::Display for TextDrawingBackend {
+    /// Renders the canvas as text, respecting [`TextDrawingBackend::mode`], matching what
+    /// [`DrawingBackend::present`] would write. This is what backs `to_string()` for
+    /// snapshotting a plot into a `String`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
 impl<'a> IntoIterator for &'a mut TextDrawingBackend {
     type IntoIter = <&'a mut Vec<PixelState> as IntoIterator>::IntoIter;
     type Item = &'a mut PixelState;
@@ -202,30 +453,90 @@ impl DrawingBackend for TextDrawingBackend {
         Ok(())
     }
 
+    /// Writes the canvas to `stderr` via [`TextDrawingBackend::present_to`]. This is monochrome
+    /// by design, ignoring [`Self::color`] — use [`TextDrawingBackend::present_colored_to`]
+    /// directly if colored output is wanted.
     fn present(&mut self) -> Result<(), DrawingErrorKind<std::io::Error>> {
         let stderr = io::stderr();
-        let mut handle = io::BufWriter::new(stderr);
-        // we aquire the lock on stderr
-        for r in 0..self.size_y {
-            let mut buf = String::new();
-            for c in 0..self.size_x {
-                buf.push(self.pixels[(r * self.size_x + c) as usize].to_char());
-            }
-            writeln!(handle, "{}", buf).map_err(DrawingErrorKind::DrawingError)?;
-        }
-
-        Ok(())
+        let mut handle = io::BufWriter::new(stderr.lock());
+        self.present_to(&mut handle)
+            .map_err(DrawingErrorKind::DrawingError)
     }
 
     #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_possible_truncation)]
     fn draw_pixel(
         &mut self,
         pos: (i32, i32),
         color: BackendColor,
     ) -> Result<(), DrawingErrorKind<std::io::Error>> {
-        if color.alpha > 0.3_f64 {
-            self.update_state(pos.0 as usize, pos.1 as usize, PixelState::Pixel);
+        if color.alpha <= 0.0_f64 {
+            return Ok(());
         }
+
+        let ramp_max = (PixelState::SHADE_RAMP.len() - 1) as f64;
+        let shade = (color.alpha * ramp_max).round().min(ramp_max) as u8;
+
+        self.update_state(pos.0 as usize, pos.1 as usize, PixelState::Shade(shade));
+        self.set_color(pos.0 as usize, pos.1 as usize, color);
+        Ok(())
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: (i32, i32),
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0_f64 {
+            return Ok(());
+        }
+
+        let (size_x, size_y) = self.get_size();
+
+        let mut outline = CirclePointCollector {
+            size_x,
+            size_y,
+            points: Vec::new(),
+        };
+        plotters_backend::rasterizer::draw_circle(&mut outline, center, radius, style, false)?;
+
+        let interior = if fill {
+            let mut filled = CirclePointCollector {
+                size_x,
+                size_y,
+                points: Vec::new(),
+            };
+            plotters_backend::rasterizer::draw_circle(&mut filled, center, radius, style, true)?;
+            let outline_set: HashSet<(i32, i32)> = outline.points.iter().copied().collect();
+            filled
+                .points
+                .into_iter()
+                .filter(|point| !outline_set.contains(point))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        for (x, y) in outline.points {
+            if x < 0_i32 || y < 0_i32 {
+                continue;
+            }
+            self.update_state(x as usize, y as usize, PixelState::Circle(false));
+            self.set_color(x as usize, y as usize, style.color());
+        }
+
+        for (x, y) in interior {
+            if x < 0_i32 || y < 0_i32 {
+                continue;
+            }
+            self.update_state(x as usize, y as usize, PixelState::Circle(true));
+            self.set_color(x as usize, y as usize, style.color());
+        }
+
         Ok(())
     }
 
@@ -242,7 +553,8 @@ impl DrawingBackend for TextDrawingBackend {
             let y0 = from.1.min(to.1);
             let y1 = from.1.max(to.1);
             for y in y0..y1 {
-                self.pixels[(y * 100_i32 + x) as usize].update(PixelState::VLine);
+                self.update_state(x as usize, y as usize, PixelState::VLine);
+                self.set_color(x as usize, y as usize, style.color());
             }
             return Ok(());
         }
@@ -252,7 +564,8 @@ impl DrawingBackend for TextDrawingBackend {
             let x0 = from.0.min(to.0);
             let x1 = from.0.max(to.0);
             for x in x0..x1 {
-                self.pixels[(y * 100_i32 + x) as usize].update(PixelState::HLine);
+                self.update_state(x as usize, y as usize, PixelState::HLine);
+                self.set_color(x as usize, y as usize, style.color());
             }
             return Ok(());
         }
@@ -260,6 +573,97 @@ impl DrawingBackend for TextDrawingBackend {
         plotters_backend::rasterizer::draw_line(self, from, to, style)
     }
 
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn fill_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: (i32, i32),
+        bottom_right: (i32, i32),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0_f64 {
+            return Ok(());
+        }
+
+        let (x0, x1) = (upper_left.0.min(bottom_right.0), upper_left.0.max(bottom_right.0));
+        let (y0, y1) = (upper_left.1.min(bottom_right.1), upper_left.1.max(bottom_right.1));
+
+        for y in y0..=y1 {
+            if y < 0_i32 {
+                continue;
+            }
+            for x in x0..=x1 {
+                if x < 0_i32 {
+                    continue;
+                }
+                let (ux, uy) = (x as usize, y as usize);
+                if y == y0 || y == y1 {
+                    self.update_state(ux, uy, PixelState::HLine);
+                }
+                if x == x0 || x == x1 {
+                    self.update_state(ux, uy, PixelState::VLine);
+                }
+                if x != x0 && x != x1 && y != y0 && y != y1 {
+                    self.update_state(ux, uy, PixelState::Fill);
+                }
+                self.set_color(ux, uy, style.color());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = (i32, i32)>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0_f64 {
+            return Ok(());
+        }
+
+        let vertices: Vec<(i32, i32)> = vert.into_iter().collect();
+        if vertices.len() < 3 {
+            return Ok(());
+        }
+
+        let y_min = vertices.iter().map(|p| p.1).min().unwrap_or(0_i32).max(0_i32);
+        let y_max = vertices.iter().map(|p| p.1).max().unwrap_or(0_i32);
+
+        for y in y_min..=y_max {
+            let mut xs = Vec::new();
+            for i in 0..vertices.len() {
+                let (x0, y0) = vertices[i];
+                let (x1, y1) = vertices[(i + 1) % vertices.len()];
+                if (y0 <= y) != (y1 <= y) {
+                    let t = f64::from(y - y0) / f64::from(y1 - y0);
+                    xs.push((f64::from(x0) + t * f64::from(x1 - x0)).round() as i32);
+                }
+            }
+            xs.sort_unstable();
+
+            for pair in xs.chunks_exact(2) {
+                let x0 = pair[0].max(0_i32);
+                let x1 = pair[1];
+                if x1 < 0_i32 {
+                    continue;
+                }
+                for x in x0..=x1 {
+                    self.update_state(x as usize, y as usize, PixelState::Fill);
+                    self.set_color(x as usize, y as usize, style.color());
+                }
+            }
+        }
+
+        for i in 0..vertices.len() {
+            self.draw_line(vertices[i], vertices[(i + 1) % vertices.len()], style)?;
+        }
+
+        Ok(())
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     fn estimate_text_size<S: BackendTextStyle>(
         &self,
@@ -289,9 +693,11 @@ impl DrawingBackend for TextDrawingBackend {
             VPos::Center => -height / 2_i32,
             VPos::Bottom => -height,
         };
-        let offset = (pos.1 + dy).max(0_i32) * 100_i32 + (pos.0 + dx).max(0_i32);
+        let size_x = self.size_x as i32;
+        let offset = (pos.1 + dy).max(0_i32) * size_x + (pos.0 + dx).max(0_i32);
         for (idx, chr) in (offset..).zip(text.chars()) {
-            self.pixels[idx as usize].update(PixelState::Text(chr));
+            let (x, y) = (idx % size_x, idx / size_x);
+            self.update_state(x as usize, y as usize, PixelState::Text(chr));
         }
         Ok(())
     }
@@ -0,0 +1,81 @@
+use crate::{PixelState, RenderMode, TextDrawingBackend};
+use plotters_backend::{BackendColor, BackendStyle, DrawingBackend};
+
+#[derive(Clone, Copy)]
+struct TestStyle(BackendColor);
+
+impl BackendStyle for TestStyle {
+    fn color(&self) -> BackendColor {
+        self.0
+    }
+}
+
+fn opaque(rgb: (u8, u8, u8)) -> TestStyle {
+    TestStyle(BackendColor { alpha: 1.0, rgb })
+}
+
+#[test]
+fn to_string_matches_present_to() {
+    let mut backend = TextDrawingBackend::new(3, 2);
+    backend.update_state(1, 0, PixelState::Text('x'));
+
+    let mut buf = Vec::new();
+    backend.present_to(&mut buf).unwrap();
+
+    assert_eq!(backend.to_string(), String::from_utf8(buf).unwrap());
+    assert_eq!(backend.to_string(), " x \n   \n");
+}
+
+#[test]
+fn draw_line_honors_non_default_size_x() {
+    let mut backend = TextDrawingBackend::new(7, 3);
+    backend
+        .draw_line((0, 1), (6, 1), &opaque((255, 0, 0)))
+        .unwrap();
+
+    for x in 0_usize..6 {
+        assert_eq!(backend.pixels()[7 + x], PixelState::HLine);
+    }
+    assert_eq!(backend.pixels()[7 + 6], PixelState::Empty);
+}
+
+#[test]
+fn braille_mode_packs_a_full_cell_into_one_glyph() {
+    let mut backend = TextDrawingBackend::new(2, 4).with_mode(RenderMode::Braille);
+    for y in 0..4 {
+        for x in 0..2 {
+            backend.update_state(x, y, PixelState::Pixel);
+        }
+    }
+
+    assert_eq!(backend.to_string(), "⣿\n");
+}
+
+#[test]
+fn draw_pixel_shade_ramp_picks_darker_glyph_for_higher_coverage() {
+    let mut backend = TextDrawingBackend::new(1, 1);
+    backend
+        .draw_pixel((0, 0), BackendColor { alpha: 0.05, rgb: (0, 0, 0) })
+        .unwrap();
+    let faint = backend.pixels()[0];
+
+    let mut backend = TextDrawingBackend::new(1, 1);
+    backend
+        .draw_pixel((0, 0), BackendColor { alpha: 0.95, rgb: (0, 0, 0) })
+        .unwrap();
+    let strong = backend.pixels()[0];
+
+    assert!(matches!(faint, PixelState::Shade(_)));
+    assert!(matches!(strong, PixelState::Shade(idx) if idx == 9));
+}
+
+#[test]
+fn faint_shade_does_not_erase_existing_line() {
+    let mut backend = TextDrawingBackend::new(1, 1);
+    backend.update_state(0, 0, PixelState::HLine);
+    backend
+        .draw_pixel((0, 0), BackendColor { alpha: 0.02, rgb: (0, 0, 0) })
+        .unwrap();
+
+    assert_eq!(backend.pixels()[0], PixelState::HLine);
+}